@@ -1,5 +1,8 @@
-use std::collections::HashMap;
-use std::env::consts;
+mod platform;
+mod units;
+
+use platform::{BatteryInfo, CurrentInfoSource, InfoSource};
+use units::format_bytes;
 
 struct SystemInfo {
     user: String,
@@ -12,21 +15,75 @@ struct SystemInfo {
     cpu_cores: String,
     cpu_speed: String,
     memory: String,
+    distro: String,
+    temperature: String,
+    battery: String,
+    disk: String,
+    gpu: String,
 }
 
 impl SystemInfo {
     fn new() -> Self {
+        let source = CurrentInfoSource::new();
+
+        // Each probe hits its own file/syscall, so run them concurrently
+        // instead of paying for every read in sequence.
+        let (
+            hostname,
+            os,
+            kernel,
+            uptime,
+            distro,
+            cpu,
+            (used_kib, total_kib),
+            temperature,
+            battery,
+            (used_disk_kib, total_disk_kib),
+            gpu,
+        ) = std::thread::scope(|scope| {
+            let hostname = scope.spawn(|| source.hostname());
+            let os = scope.spawn(|| source.os_info());
+            let kernel = scope.spawn(|| source.kernel());
+            let uptime = scope.spawn(|| Self::format_uptime(source.uptime_secs()));
+            let distro = scope.spawn(|| source.distro_id());
+            let cpu = scope.spawn(|| source.cpu_info());
+            let memory = scope.spawn(|| source.memory_kib());
+            let temperature = scope.spawn(|| source.temperature_celsius());
+            let battery = scope.spawn(|| source.battery());
+            let disk = scope.spawn(|| source.disk_kib());
+            let gpu = scope.spawn(|| source.gpu());
+
+            (
+                hostname.join().unwrap(),
+                os.join().unwrap(),
+                kernel.join().unwrap(),
+                uptime.join().unwrap(),
+                distro.join().unwrap(),
+                cpu.join().unwrap(),
+                memory.join().unwrap(),
+                temperature.join().unwrap(),
+                battery.join().unwrap(),
+                disk.join().unwrap(),
+                gpu.join().unwrap(),
+            )
+        });
+
         Self {
             user: Self::get_user(),
-            hostname: Self::get_hostname(),
-            os: Self::get_os_info(),
-            kernel: Self::get_kernel(),
-            uptime: Self::get_uptime(),
+            hostname,
+            os,
+            kernel,
+            uptime,
             shell: Self::get_shell(),
-            cpu: Self::get_cpu(),
-            cpu_cores: Self::get_cpu_cores(),
-            cpu_speed: Self::get_cpu_speed(),
-            memory: Self::get_memory(),
+            cpu: cpu.model,
+            cpu_cores: format!("{}", cpu.cores),
+            cpu_speed: format!("{:.2}GHz", cpu.speed_ghz),
+            memory: Self::format_memory(used_kib, total_kib),
+            distro,
+            temperature: Self::format_temperature(temperature),
+            battery: Self::format_battery(battery),
+            disk: Self::format_memory(used_disk_kib, total_disk_kib),
+            gpu: gpu.unwrap_or_else(|| "unknown".to_string()),
         }
     }
 
@@ -34,117 +91,57 @@ impl SystemInfo {
         std::env::var("USER").unwrap_or_else(|_| "unknown".to_string())
     }
 
-    fn get_hostname() -> String {
-        std::fs::read_to_string("/proc/sys/kernel/hostname")
-            .unwrap_or_else(|_| "unknown".to_string())
-            .trim()
-            .to_string()
+    fn get_shell() -> String {
+        std::env::var("SHELL").unwrap_or_else(|_| "unknown".to_string())
     }
 
-    fn get_os_info() -> String {
-        if let Ok(content) = std::fs::read_to_string("/etc/os-release") {
-            for line in content.lines() {
-                if line.starts_with("PRETTY_NAME=") {
-                    return line.trim_start_matches("PRETTY_NAME=")
-                        .trim_matches('"')
-                        .to_string();
-                }
-            }
-        }
-        format!("{} {}", consts::OS, consts::ARCH)
-    }
+    fn format_uptime(seconds: Option<f64>) -> String {
+        let Some(seconds) = seconds else {
+            return "unknown".to_string();
+        };
 
-    fn get_kernel() -> String {
-        std::fs::read_to_string("/proc/sys/kernel/osrelease")
-            .unwrap_or_else(|_| "unknown".to_string())
-            .trim()
-            .to_string()
-    }
+        let total_seconds = seconds as u64;
+        let days = total_seconds / 86_400;
+        let hours = (total_seconds % 86_400) / 3600;
+        let minutes = (total_seconds % 3600) / 60;
+        let secs = total_seconds % 60;
 
-    fn get_uptime() -> String {
-        if let Ok(content) = std::fs::read_to_string("/proc/uptime") {
-            if let Some(uptime_seconds) = content.split_whitespace().next() {
-                if let Ok(seconds) = uptime_seconds.parse::<f64>() {
-                    let hours = (seconds / 3600.0) as u32;
-                    let minutes = ((seconds % 3600.0) / 60.0) as u32;
-                    return format!("{}h {}m", hours, minutes);
-                }
-            }
+        let mut parts = Vec::new();
+        if days > 0 {
+            parts.push(format!("{}d", days));
         }
-        "unknown".to_string()
-    }
-
-    fn get_shell() -> String {
-        std::env::var("SHELL").unwrap_or_else(|_| "unknown".to_string())
-    }
-
-    fn get_cpu() -> String {
-        if let Ok(content) = std::fs::read_to_string("/proc/cpuinfo") {
-            for line in content.lines() {
-                if line.starts_with("model name") {
-                    if let Some(name) = line.split(':').nth(1) {
-                        let full_name = name.trim().to_string();
-                        if full_name.len() > 30 {
-                            return format!("{}...", &full_name[..27]);
-                        }
-                        return full_name;
-                    }
-                }
-            }
+        if hours > 0 || days > 0 {
+            parts.push(format!("{}h", hours));
+        }
+        if minutes > 0 || !parts.is_empty() {
+            parts.push(format!("{}m", minutes));
+        }
+        if parts.is_empty() {
+            parts.push(format!("{}s", secs));
         }
-        "unknown".to_string()
+
+        parts.join(" ")
     }
 
-    fn get_cpu_cores() -> String {
-        if let Ok(content) = std::fs::read_to_string("/proc/cpuinfo") {
-            let cores = content.lines()
-                .filter(|line| line.starts_with("processor"))
-                .count();
-            format!("{}", cores)
-        } else {
-            "unknown".to_string()
+    fn format_memory(used_kib: u64, total_kib: u64) -> String {
+        if total_kib == 0 {
+            return "unknown".to_string();
         }
+        format!("{} / {}", format_bytes(used_kib), format_bytes(total_kib))
     }
 
-    fn get_cpu_speed() -> String {
-        if let Ok(content) = std::fs::read_to_string("/proc/cpuinfo") {
-            for line in content.lines() {
-                if line.starts_with("cpu MHz") {
-                    if let Some(speed) = line.split(':').nth(1) {
-                        let mhz = speed.trim().parse::<f64>().unwrap_or(0.0);
-                        let ghz = mhz / 1000.0;
-                        return format!("{:.2}GHz", ghz);
-                    }
-                }
-            }
+    fn format_temperature(celsius: Option<f64>) -> String {
+        match celsius {
+            Some(celsius) => format!("{:.1}°C", celsius),
+            None => "unknown".to_string(),
         }
-        "unknown".to_string()
     }
 
-    fn get_memory() -> String {
-        if let Ok(content) = std::fs::read_to_string("/proc/meminfo") {
-            let mut mem_info = HashMap::new();
-            for line in content.lines() {
-                if let Some((key, value)) = line.split_once(':') {
-                    let num: u64 = value.trim()
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or("0")
-                        .parse()
-                        .unwrap_or(0);
-                    mem_info.insert(key.trim(), num);
-                }
-            }
-
-            if let (Some(&total), Some(&available)) = 
-                (mem_info.get("MemTotal"), mem_info.get("MemAvailable")) {
-                let used = total - available;
-                let used_gb = used as f64 / 1024.0 / 1024.0;
-                let total_gb = total as f64 / 1024.0 / 1024.0;
-                return format!("{:.1}GB / {:.1}GB", used_gb, total_gb);
-            }
+    fn format_battery(battery: Option<BatteryInfo>) -> String {
+        match battery {
+            Some(battery) => format!("{}% ({})", battery.percent, battery.status),
+            None => "N/A".to_string(),
         }
-        "unknown".to_string()
     }
 }
 
@@ -165,18 +162,87 @@ fn get_logo() -> Vec<&'static str> {
     ]
 }
 
-fn get_color_code() -> &'static str {
-    "\x1b[36m" // cyan
+/// Picks an ASCII logo and accent color for a `distro_id` string like
+/// `"ubuntu debian"` (space-separated `ID`/`ID_LIKE` values). Falls back to
+/// the ant logo when the distro isn't recognized.
+fn get_logo_for(distro: &str) -> (Vec<&'static str>, &'static str) {
+    const RESET_CYAN: &str = "\x1b[36m";
+
+    if distro.contains("arch") {
+        (
+            vec![
+                "       /\\",
+                "      /  \\",
+                "     /\\   \\",
+                "    /      \\",
+                "   /   ,,   \\",
+                "  /   |  |   \\",
+                " /_-''    ''-_\\",
+            ],
+            "\x1b[36m", // cyan
+        )
+    } else if distro.contains("ubuntu") {
+        (
+            vec![
+                "         _",
+                "     ---(_)",
+                " _/  ---  \\",
+                "(_) |     |",
+                " \\  --- _/",
+                "     ---(_)",
+            ],
+            "\x1b[31m", // orange-ish (256-color not assumed)
+        )
+    } else if distro.contains("debian") {
+        (
+            vec![
+                "  _____",
+                " /  __ \\",
+                "|  /    |",
+                "|  \\___-",
+                "-_",
+                "  --_",
+            ],
+            "\x1b[31m", // red
+        )
+    } else if distro.contains("fedora") {
+        (
+            vec![
+                "      _____",
+                "     /   __)\\",
+                "     |  /  \\ \\",
+                "  ___|  |__/ /",
+                " / (_    _)_/",
+                " \\___|  |",
+                "     |  |",
+                "     |__|",
+            ],
+            "\x1b[34m", // blue
+        )
+    } else if distro.contains("alpine") {
+        (
+            vec![
+                "   /\\ /\\",
+                "  /  \\  \\",
+                " /    \\  \\",
+                "/      \\  \\",
+                "  /\\  /\\  \\",
+                " /  \\/  \\  \\",
+            ],
+            "\x1b[34m", // blue
+        )
+    } else {
+        (get_logo(), RESET_CYAN)
+    }
 }
 
 fn main() {
     let info = SystemInfo::new();
-    let logo = get_logo();
-    let color = get_color_code();
+    let (logo, color) = get_logo_for(&info.distro);
     let reset = "\x1b[0m";
 
     let user_host = format!("{}{}@{}{}", color, info.user, info.hostname, reset);
-    
+
     let labels = vec![
         ("OS", info.os),
         ("Host", info.hostname.clone()),
@@ -185,30 +251,36 @@ fn main() {
         ("Shell", info.shell),
         ("CPU", format!("{} ({}) @ {}", info.cpu, info.cpu_cores, info.cpu_speed)),
         ("Memory", info.memory),
+        ("Disk", info.disk),
+        ("GPU", info.gpu),
+        ("Temp", info.temperature),
+        ("Battery", info.battery),
     ];
 
     let text_start_position = 30;
-    
+
     let total_width = text_start_position + 30;
 
     println!("{:>width$}", user_host, width = total_width);
 
-    for i in 0..logo.len() {
-        if i >= 1 && i <= 7 {
+    let rows = logo.len().max(labels.len() + 1);
+    for i in 0..rows {
+        let logo_line = logo.get(i).copied().unwrap_or("");
+
+        if i >= 1 && i - 1 < labels.len() {
             let (label, value) = &labels[i - 1];
             let info_text = format!("{}{}:{} {}", color, label, reset, value);
-            
-            let current_logo_length = logo[i].len();
-            let padding_needed = if text_start_position > current_logo_length {
-                text_start_position - current_logo_length
+
+            let padding_needed = if text_start_position > logo_line.len() {
+                text_start_position - logo_line.len()
             } else {
                 1
             };
-            
-            print!("{}{}{}", color, logo[i], reset);
+
+            print!("{}{}{}", color, logo_line, reset);
             println!("{:width$}{}", "", info_text, width = padding_needed);
         } else {
-            println!("{}{}{}", color, logo[i], reset);
+            println!("{}{}{}", color, logo_line, reset);
         }
     }
 }
\ No newline at end of file
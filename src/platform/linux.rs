@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::os::raw::c_char;
+
+use super::{truncate_cpu_model, BatteryInfo, CpuInfo, InfoSource};
+
+/// Mirrors glibc's `struct statvfs` (64-bit Linux layout) so `disk_kib` can
+/// call it directly without pulling in a `libc` dependency.
+#[repr(C)]
+struct StatVfs {
+    f_bsize: u64,
+    f_frsize: u64,
+    f_blocks: u64,
+    f_bfree: u64,
+    f_bavail: u64,
+    f_files: u64,
+    f_ffree: u64,
+    f_favail: u64,
+    f_fsid: u64,
+    f_flag: u64,
+    f_namemax: u64,
+    f_spare: [i32; 6],
+}
+
+extern "C" {
+    fn statvfs(path: *const c_char, buf: *mut StatVfs) -> i32;
+}
+
+/// Reads `/proc` and `/etc` the way most Linux fetch tools do.
+pub struct LinuxInfoSource;
+
+impl LinuxInfoSource {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl InfoSource for LinuxInfoSource {
+    fn hostname(&self) -> String {
+        std::fs::read_to_string("/proc/sys/kernel/hostname")
+            .unwrap_or_else(|_| "unknown".to_string())
+            .trim()
+            .to_string()
+    }
+
+    fn os_info(&self) -> String {
+        if let Ok(content) = std::fs::read_to_string("/etc/os-release") {
+            for line in content.lines() {
+                if line.starts_with("PRETTY_NAME=") {
+                    return line
+                        .trim_start_matches("PRETTY_NAME=")
+                        .trim_matches('"')
+                        .to_string();
+                }
+            }
+        }
+        format!("{} {}", std::env::consts::OS, std::env::consts::ARCH)
+    }
+
+    fn kernel(&self) -> String {
+        std::fs::read_to_string("/proc/sys/kernel/osrelease")
+            .unwrap_or_else(|_| "unknown".to_string())
+            .trim()
+            .to_string()
+    }
+
+    fn uptime_secs(&self) -> Option<f64> {
+        let content = std::fs::read_to_string("/proc/uptime").ok()?;
+        content.split_whitespace().next()?.parse().ok()
+    }
+
+    fn cpu_info(&self) -> CpuInfo {
+        let content = std::fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+
+        let mut model = "unknown".to_string();
+        let mut cores = 0usize;
+        let mut speed_ghz = 0.0;
+
+        for line in content.lines() {
+            if line.starts_with("model name") {
+                if let Some(name) = line.split(':').nth(1) {
+                    model = truncate_cpu_model(name.trim());
+                }
+            } else if line.starts_with("processor") {
+                cores += 1;
+            } else if line.starts_with("cpu MHz") && speed_ghz == 0.0 {
+                if let Some(speed) = line.split(':').nth(1) {
+                    let mhz = speed.trim().parse::<f64>().unwrap_or(0.0);
+                    speed_ghz = mhz / 1000.0;
+                }
+            }
+        }
+
+        CpuInfo {
+            model,
+            cores,
+            speed_ghz,
+        }
+    }
+
+    fn distro_id(&self) -> String {
+        let Ok(content) = std::fs::read_to_string("/etc/os-release") else {
+            return String::new();
+        };
+
+        let mut ids = Vec::new();
+        for line in content.lines() {
+            if let Some(value) = line.strip_prefix("ID=") {
+                ids.push(value.trim_matches('"').to_lowercase());
+            } else if let Some(value) = line.strip_prefix("ID_LIKE=") {
+                ids.extend(value.trim_matches('"').to_lowercase().split_whitespace().map(str::to_string));
+            }
+        }
+        ids.join(" ")
+    }
+
+    fn memory_kib(&self) -> (u64, u64) {
+        if let Ok(content) = std::fs::read_to_string("/proc/meminfo") {
+            let mut mem_info = HashMap::new();
+            for line in content.lines() {
+                if let Some((key, value)) = line.split_once(':') {
+                    let num: u64 = value
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or("0")
+                        .parse()
+                        .unwrap_or(0);
+                    mem_info.insert(key.trim(), num);
+                }
+            }
+
+            if let (Some(&total), Some(&available)) =
+                (mem_info.get("MemTotal"), mem_info.get("MemAvailable"))
+            {
+                return (total - available, total);
+            }
+        }
+        (0, 0)
+    }
+
+    fn temperature_celsius(&self) -> Option<f64> {
+        let hwmon = std::fs::read_dir("/sys/class/hwmon").ok()?;
+        for entry in hwmon.flatten() {
+            let path = entry.path();
+            let name = std::fs::read_to_string(path.join("name")).unwrap_or_default();
+            let name = name.trim();
+            if !matches!(name, "coretemp" | "k10temp" | "cpu_thermal" | "zenpower") {
+                continue;
+            }
+
+            let mut inputs: Vec<_> = std::fs::read_dir(&path)
+                .ok()?
+                .flatten()
+                .map(|e| e.file_name().to_string_lossy().into_owned())
+                .filter(|f| f.ends_with("_input") && f.starts_with("temp"))
+                .collect();
+            inputs.sort();
+
+            for input in inputs {
+                if let Ok(raw) = std::fs::read_to_string(path.join(&input)) {
+                    if let Ok(millidegrees) = raw.trim().parse::<f64>() {
+                        return Some(millidegrees / 1000.0);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn battery(&self) -> Option<BatteryInfo> {
+        let power_supply = std::fs::read_dir("/sys/class/power_supply").ok()?;
+        for entry in power_supply.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !name.starts_with("BAT") {
+                continue;
+            }
+
+            let Some(percent) = std::fs::read_to_string(path.join("capacity"))
+                .ok()
+                .and_then(|raw| raw.trim().parse().ok())
+            else {
+                continue;
+            };
+            let status = std::fs::read_to_string(path.join("status"))
+                .unwrap_or_else(|_| "Unknown".to_string())
+                .trim()
+                .to_string();
+
+            return Some(BatteryInfo { percent, status });
+        }
+        None
+    }
+
+    fn disk_kib(&self) -> (u64, u64) {
+        use std::ffi::CString;
+        use std::mem::MaybeUninit;
+
+        let Ok(path) = CString::new("/") else {
+            return (0, 0);
+        };
+
+        let mut stat = MaybeUninit::<StatVfs>::uninit();
+        // SAFETY: `path` is a valid NUL-terminated C string and `stat` is a
+        // valid, suitably-sized buffer for `statvfs` to write into.
+        let rc = unsafe { statvfs(path.as_ptr(), stat.as_mut_ptr()) };
+        if rc != 0 {
+            return (0, 0);
+        }
+        // SAFETY: `statvfs` returned success, so it fully initialized `stat`.
+        let stat = unsafe { stat.assume_init() };
+
+        let total_kib = stat.f_blocks * stat.f_frsize / 1024;
+        let avail_kib = stat.f_bavail * stat.f_frsize / 1024;
+
+        (total_kib.saturating_sub(avail_kib), total_kib)
+    }
+
+    fn gpu(&self) -> Option<String> {
+        let output = std::process::Command::new("lspci").output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find(|line| line.contains("VGA compatible controller") || line.contains("3D controller"))
+            .and_then(|line| line.split(": ").nth(1))
+            .map(str::to_string)
+    }
+}
@@ -0,0 +1,317 @@
+use std::ffi::{c_void, CStr, CString};
+use std::mem::MaybeUninit;
+use std::os::raw::{c_char, c_int};
+use std::process::Command;
+
+use super::{truncate_cpu_model, BatteryInfo, CpuInfo, InfoSource};
+
+extern "C" {
+    fn sysctlbyname(
+        name: *const c_char,
+        oldp: *mut c_void,
+        oldlenp: *mut usize,
+        newp: *const c_void,
+        newlen: usize,
+    ) -> c_int;
+
+    fn statfs(path: *const c_char, buf: *mut StatFs) -> c_int;
+
+    fn mach_host_self() -> u32;
+    fn host_page_size(host: u32, out_page_size: *mut u64) -> c_int;
+    fn host_statistics64(
+        host_priv: u32,
+        flavor: c_int,
+        host_info_out: *mut c_void,
+        host_info_out_count: *mut u32,
+    ) -> c_int;
+}
+
+/// Mirrors BSD's `struct statfs` (64-bit macOS layout) closely enough for
+/// `disk_kib`; the trailing path buffers are never read so they're kept as
+/// plain byte arrays rather than typed out field-by-field.
+#[repr(C)]
+struct StatFs {
+    f_bsize: u32,
+    f_iosize: i32,
+    f_blocks: u64,
+    f_bfree: u64,
+    f_bavail: u64,
+    f_files: u64,
+    f_ffree: u64,
+    f_fsid: [i32; 2],
+    f_owner: u32,
+    f_type: u32,
+    f_flags: u32,
+    f_fssubtype: u32,
+    f_fstypename: [u8; 16],
+    f_mntonname: [u8; 1024],
+    f_mntfromname: [u8; 1024],
+    f_reserved: [u32; 8],
+}
+
+/// Mirrors Mach's `vm_statistics64_data_t`; only the page-count fields this
+/// source reads are named precisely, but the struct's total size still has
+/// to match the real one since `host_statistics64` writes fixed offsets.
+#[repr(C)]
+#[derive(Default)]
+struct VmStatistics64 {
+    free_count: u32,
+    active_count: u32,
+    inactive_count: u32,
+    wire_count: u32,
+    zero_fill_count: u64,
+    reactivations: u64,
+    pageins: u64,
+    pageouts: u64,
+    faults: u64,
+    cow_faults: u64,
+    lookups: u64,
+    hits: u64,
+    purges: u64,
+    purgeable_count: u32,
+    speculative_count: u32,
+    decompressions: u64,
+    compressions: u64,
+    swapins: u64,
+    swapouts: u64,
+    compressor_page_count: u32,
+    throttled_count: u32,
+    external_page_count: u32,
+    internal_page_count: u32,
+    total_uncompressed_pages_in_compressor: u64,
+}
+
+const HOST_VM_INFO64: c_int = 4;
+
+/// Queries a `sysctlbyname` string (e.g. `kern.hostname`), trimming the
+/// trailing NUL the kernel includes in the byte count.
+fn sysctl_string(name: &str) -> Option<String> {
+    let bytes = sysctl_bytes(name)?;
+    let cstr = CStr::from_bytes_until_nul(&bytes).ok()?;
+    Some(cstr.to_string_lossy().into_owned())
+}
+
+/// Queries a `sysctlbyname` value into a fixed-size buffer, for the
+/// fixed-width integer sysctls (`hw.memsize`, `hw.physicalcpu`, ...).
+fn sysctl_fixed<T: Default>(name: &str) -> Option<T> {
+    let cname = CString::new(name).ok()?;
+    let mut value = T::default();
+    let mut len = std::mem::size_of::<T>();
+    // SAFETY: `value` is a valid, correctly-sized buffer for `len` bytes and
+    // `cname` is a NUL-terminated sysctl name.
+    let rc = unsafe {
+        sysctlbyname(
+            cname.as_ptr(),
+            &mut value as *mut T as *mut c_void,
+            &mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if rc != 0 {
+        return None;
+    }
+    Some(value)
+}
+
+/// Queries a `sysctlbyname` value of unknown length (strings, `boottime`'s
+/// variable-padded `timeval`) via the standard two-call size-then-fetch
+/// protocol.
+fn sysctl_bytes(name: &str) -> Option<Vec<u8>> {
+    let cname = CString::new(name).ok()?;
+    let mut len = 0usize;
+    // SAFETY: a null `oldp` just asks sysctlbyname to report the size in `len`.
+    let rc = unsafe {
+        sysctlbyname(cname.as_ptr(), std::ptr::null_mut(), &mut len, std::ptr::null(), 0)
+    };
+    if rc != 0 || len == 0 {
+        return None;
+    }
+
+    let mut buf = vec![0u8; len];
+    // SAFETY: `buf` is sized exactly to what the first call reported.
+    let rc = unsafe {
+        sysctlbyname(
+            cname.as_ptr(),
+            buf.as_mut_ptr() as *mut c_void,
+            &mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+    if rc != 0 {
+        return None;
+    }
+    buf.truncate(len);
+    Some(buf)
+}
+
+/// Reads `/System/Library/CoreServices/SystemVersion.plist` directly instead
+/// of shelling out to `sw_vers`, pulling `ProductVersion` out of its simple
+/// XML `<key>`/`<string>` pairing.
+fn product_version() -> Option<String> {
+    let plist = std::fs::read_to_string("/System/Library/CoreServices/SystemVersion.plist").ok()?;
+    let key_pos = plist.find("<key>ProductVersion</key>")?;
+    let after_key = &plist[key_pos..];
+    let value_start = after_key.find("<string>")? + "<string>".len();
+    let value_end = after_key[value_start..].find("</string>")?;
+    Some(after_key[value_start..value_start + value_end].to_string())
+}
+
+/// Reads the handful of system facts that only BSD/Mach syscalls expose
+/// (`sysctlbyname`, `host_statistics64`, `statfs`) instead of shelling out,
+/// the way `LinuxInfoSource` reads `/proc` directly.
+///
+/// `battery()` and `gpu()` are the exception: their real data lives behind
+/// IOKit/CoreFoundation, which would need a much larger hand-rolled binding
+/// surface than a couple of scalar `sysctlbyname` calls. Those two stay on
+/// `pmset`/`system_profiler` as a deliberate best-effort scope, not an
+/// oversight.
+pub struct MacInfoSource;
+
+impl MacInfoSource {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl InfoSource for MacInfoSource {
+    fn hostname(&self) -> String {
+        sysctl_string("kern.hostname").unwrap_or_else(|| "unknown".to_string())
+    }
+
+    fn os_info(&self) -> String {
+        match product_version() {
+            Some(version) => format!("macOS {version}"),
+            None => format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+        }
+    }
+
+    fn kernel(&self) -> String {
+        sysctl_string("kern.osrelease").unwrap_or_else(|| "unknown".to_string())
+    }
+
+    fn uptime_secs(&self) -> Option<f64> {
+        // `kern.boottime` is a `struct timeval`; the 8-byte `tv_sec` always
+        // comes first regardless of the padding macOS adds after `tv_usec`.
+        let bytes = sysctl_bytes("kern.boottime")?;
+        let sec_bytes: [u8; 8] = bytes.get(..8)?.try_into().ok()?;
+        let sec = i64::from_ne_bytes(sec_bytes) as f64;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs_f64();
+        Some((now - sec).max(0.0))
+    }
+
+    fn cpu_info(&self) -> CpuInfo {
+        let model = sysctl_string("machdep.cpu.brand_string").unwrap_or_else(|| "unknown".to_string());
+        let model = truncate_cpu_model(&model);
+        let cores = sysctl_fixed::<i32>("hw.physicalcpu").unwrap_or(0) as usize;
+        let speed_ghz = sysctl_fixed::<u64>("hw.cpufrequency")
+            .map(|hz| hz as f64 / 1_000_000_000.0)
+            .unwrap_or(0.0);
+
+        CpuInfo {
+            model,
+            cores,
+            speed_ghz,
+        }
+    }
+
+    fn memory_kib(&self) -> (u64, u64) {
+        let total_kib = sysctl_fixed::<u64>("hw.memsize").unwrap_or(0) / 1024;
+
+        let used_kib = (|| {
+            let host = unsafe { mach_host_self() };
+
+            let mut page_size = 0u64;
+            // SAFETY: `host` is a valid host port from `mach_host_self` and
+            // `page_size` is a valid output slot.
+            if unsafe { host_page_size(host, &mut page_size) } != 0 {
+                return None;
+            }
+
+            let mut stats = VmStatistics64::default();
+            let mut count = (std::mem::size_of::<VmStatistics64>() / std::mem::size_of::<u32>()) as u32;
+            // SAFETY: `stats` is sized to `count` 32-bit words, matching what
+            // `host_statistics64` expects to write for `HOST_VM_INFO64`.
+            let rc = unsafe {
+                host_statistics64(
+                    host,
+                    HOST_VM_INFO64,
+                    &mut stats as *mut VmStatistics64 as *mut c_void,
+                    &mut count,
+                )
+            };
+            if rc != 0 {
+                return None;
+            }
+
+            let used_pages = stats.active_count as u64
+                + stats.wire_count as u64
+                + stats.compressor_page_count as u64;
+            Some(used_pages * page_size / 1024)
+        })()
+        .unwrap_or(0);
+
+        (used_kib, total_kib)
+    }
+
+    fn battery(&self) -> Option<BatteryInfo> {
+        let output = Command::new("pmset").args(["-g", "batt"]).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let text = String::from_utf8_lossy(&output.stdout);
+        let line = text.lines().nth(1)?;
+
+        let percent: u8 = line.split('\t').nth(1)?.split('%').next()?.parse().ok()?;
+        let status = if line.contains("charging") && !line.contains("discharging") {
+            "Charging".to_string()
+        } else if line.contains("charged") {
+            "Full".to_string()
+        } else {
+            "Discharging".to_string()
+        };
+
+        Some(BatteryInfo { percent, status })
+    }
+
+    fn disk_kib(&self) -> (u64, u64) {
+        let Ok(path) = CString::new("/") else {
+            return (0, 0);
+        };
+
+        let mut stat = MaybeUninit::<StatFs>::uninit();
+        // SAFETY: `path` is a valid NUL-terminated C string and `stat` is a
+        // valid, suitably-sized buffer for `statfs` to write into.
+        let rc = unsafe { statfs(path.as_ptr(), stat.as_mut_ptr()) };
+        if rc != 0 {
+            return (0, 0);
+        }
+        // SAFETY: `statfs` returned success, so it fully initialized `stat`.
+        let stat = unsafe { stat.assume_init() };
+
+        let block_kib = stat.f_bsize as u64 / 1024;
+        let total_kib = stat.f_blocks * block_kib;
+        let avail_kib = stat.f_bavail * block_kib;
+
+        (total_kib.saturating_sub(avail_kib), total_kib)
+    }
+
+    fn gpu(&self) -> Option<String> {
+        let output = Command::new("system_profiler")
+            .arg("SPDisplaysDataType")
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("Chipset Model: "))
+            .map(str::to_string)
+    }
+}
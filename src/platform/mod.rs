@@ -0,0 +1,98 @@
+//! Per-platform system probes.
+//!
+//! `SystemInfo` talks to a single [`InfoSource`] implementation so that the
+//! rest of the crate never has to care whether it is running on Linux,
+//! macOS, or Windows. Each platform module owns the raw reads (`/proc` on
+//! Linux, `sysctl`/Mach calls on macOS, the Win32 APIs on Windows) and hands
+//! back plain data. The exceptions shell out: macOS's `battery()`/`gpu()`
+//! (`pmset`/`system_profiler`, since their real data lives behind
+//! IOKit/CoreFoundation) and Linux's `gpu()` (`lspci`).
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "windows")]
+mod windows;
+
+#[cfg(target_os = "linux")]
+pub use linux::LinuxInfoSource as CurrentInfoSource;
+#[cfg(target_os = "macos")]
+pub use macos::MacInfoSource as CurrentInfoSource;
+#[cfg(target_os = "windows")]
+pub use windows::WindowsInfoSource as CurrentInfoSource;
+
+/// Truncates a CPU model string to 30 characters, appending `...` if it was
+/// cut short.
+///
+/// Slicing by byte index (`&s[..27]`) panics when the cut falls in the
+/// middle of a multi-byte character (e.g. a trademark glyph in a brand
+/// string), so every platform source should go through this instead.
+pub fn truncate_cpu_model(model: &str) -> String {
+    const MAX_CHARS: usize = 30;
+    const KEEP_CHARS: usize = MAX_CHARS - 3;
+
+    if model.chars().count() <= MAX_CHARS {
+        model.to_string()
+    } else {
+        format!("{}...", model.chars().take(KEEP_CHARS).collect::<String>())
+    }
+}
+
+/// CPU model, core count, and clock speed gathered from a single probe.
+///
+/// Bundling the three together means a platform only has to read its
+/// underlying source (e.g. `/proc/cpuinfo`) once instead of once per field.
+pub struct CpuInfo {
+    pub model: String,
+    pub cores: usize,
+    pub speed_ghz: f64,
+}
+
+/// Battery charge and charging state, as reported by the OS power subsystem.
+pub struct BatteryInfo {
+    pub percent: u8,
+    pub status: String,
+}
+
+/// A platform-specific source of raw system information.
+///
+/// Implementations should favor returning `"unknown"` / zeroed values over
+/// panicking — probes routinely fail in containers, VMs, and sandboxes that
+/// don't expose the usual files or APIs.
+pub trait InfoSource {
+    fn hostname(&self) -> String;
+    fn os_info(&self) -> String;
+    fn kernel(&self) -> String;
+    fn uptime_secs(&self) -> Option<f64>;
+    fn cpu_info(&self) -> CpuInfo;
+    /// Returns `(used_kib, total_kib)`.
+    fn memory_kib(&self) -> (u64, u64);
+
+    /// Lowercased `ID` and `ID_LIKE` values (space-separated) for distro
+    /// detection, e.g. `"ubuntu debian"`. Empty when the platform has no
+    /// such concept (macOS, Windows) or the probe fails.
+    fn distro_id(&self) -> String {
+        String::new()
+    }
+
+    /// CPU package/zone temperature in degrees Celsius, when a sensor is
+    /// exposed and readable.
+    fn temperature_celsius(&self) -> Option<f64> {
+        None
+    }
+
+    /// `None` when there is no battery (desktops, most servers).
+    fn battery(&self) -> Option<BatteryInfo> {
+        None
+    }
+
+    /// Returns `(used_kib, total_kib)` for the root/system volume.
+    fn disk_kib(&self) -> (u64, u64) {
+        (0, 0)
+    }
+
+    fn gpu(&self) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,337 @@
+use std::ffi::c_void;
+use std::mem::MaybeUninit;
+use std::os::raw::c_ulong;
+
+use super::{truncate_cpu_model, BatteryInfo, CpuInfo, InfoSource};
+
+type Bool = i32;
+type Hkey = isize;
+
+const HKEY_LOCAL_MACHINE: Hkey = -0x7FFFFFFE; // 0x80000002 as a signed handle
+const RRF_RT_REG_SZ: u32 = 0x0000_0002;
+const RRF_RT_REG_DWORD: u32 = 0x0000_0010;
+const COMPUTER_NAME_DNS_HOSTNAME: u32 = 1;
+
+extern "system" {
+    fn GetComputerNameExW(name_type: u32, buffer: *mut u16, size: *mut u32) -> Bool;
+    fn GetTickCount64() -> u64;
+    fn GetSystemInfo(info: *mut SystemInfo);
+    fn GlobalMemoryStatusEx(status: *mut MemoryStatusEx) -> Bool;
+    fn GetSystemPowerStatus(status: *mut SystemPowerStatus) -> Bool;
+    fn GetDiskFreeSpaceExW(
+        directory: *const u16,
+        free_bytes_available: *mut u64,
+        total_bytes: *mut u64,
+        total_free_bytes: *mut u64,
+    ) -> Bool;
+    fn EnumDisplayDevicesW(device: *const u16, dev_num: u32, info: *mut DisplayDeviceW, flags: u32) -> Bool;
+    fn RegGetValueW(
+        hkey: Hkey,
+        sub_key: *const u16,
+        value: *const u16,
+        flags: u32,
+        value_type: *mut u32,
+        data: *mut c_void,
+        data_size: *mut c_ulong,
+    ) -> i32;
+}
+
+#[link(name = "ntdll")]
+extern "system" {
+    /// Queries the real OS version without going through `GetVersionEx`,
+    /// which has lied about the version since the Windows 8.1 compatibility
+    /// shims landed.
+    fn RtlGetVersion(info: *mut OsVersionInfoW) -> i32;
+}
+
+/// Mirrors `OSVERSIONINFOW`.
+#[repr(C)]
+struct OsVersionInfoW {
+    dw_os_version_info_size: u32,
+    dw_major_version: u32,
+    dw_minor_version: u32,
+    dw_build_number: u32,
+    dw_platform_id: u32,
+    sz_csd_version: [u16; 128],
+}
+
+impl Default for OsVersionInfoW {
+    fn default() -> Self {
+        // SAFETY: an all-zero `OSVERSIONINFOW` is a valid value; the caller
+        // sets `dw_os_version_info_size` before passing it to `RtlGetVersion`.
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// Mirrors `SYSTEM_INFO`; only `dw_number_of_processors` is read, but the
+/// fields ahead of it still have to line up for that offset to be correct.
+#[repr(C)]
+#[derive(Default)]
+struct SystemInfo {
+    w_processor_architecture: u16,
+    w_reserved: u16,
+    dw_page_size: u32,
+    lp_minimum_application_address: usize,
+    lp_maximum_application_address: usize,
+    dw_active_processor_mask: usize,
+    dw_number_of_processors: u32,
+    dw_processor_type: u32,
+    dw_allocation_granularity: u32,
+    w_processor_level: u16,
+    w_processor_revision: u16,
+}
+
+/// Mirrors `MEMORYSTATUSEX`.
+#[repr(C)]
+struct MemoryStatusEx {
+    dw_length: u32,
+    dw_memory_load: u32,
+    ull_total_phys: u64,
+    ull_avail_phys: u64,
+    ull_total_page_file: u64,
+    ull_avail_page_file: u64,
+    ull_total_virtual: u64,
+    ull_avail_virtual: u64,
+    ull_avail_extended_virtual: u64,
+}
+
+/// Mirrors `SYSTEM_POWER_STATUS`.
+#[repr(C)]
+#[derive(Default)]
+struct SystemPowerStatus {
+    ac_line_status: u8,
+    battery_flag: u8,
+    battery_life_percent: u8,
+    system_status_flag: u8,
+    battery_life_time: u32,
+    battery_full_life_time: u32,
+}
+
+const BATTERY_FLAG_NO_BATTERY: u8 = 128;
+const BATTERY_FLAG_CHARGING: u8 = 8;
+
+/// Mirrors `DISPLAY_DEVICEW`.
+#[repr(C)]
+struct DisplayDeviceW {
+    cb: u32,
+    device_name: [u16; 32],
+    device_string: [u16; 128],
+    state_flags: u32,
+    device_id: [u16; 128],
+    device_key: [u16; 128],
+}
+
+impl Default for DisplayDeviceW {
+    fn default() -> Self {
+        // SAFETY: an all-zero `DISPLAY_DEVICEW` is a valid value; `cb` is set
+        // separately before the struct is passed to `EnumDisplayDevicesW`.
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+fn to_wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+fn from_wide(buf: &[u16]) -> String {
+    let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+    String::from_utf16_lossy(&buf[..len])
+}
+
+/// Reads a `REG_SZ` value under `HARDWARE\DESCRIPTION\System\CentralProcessor\0`.
+fn cpu_registry_string(value: &str) -> Option<String> {
+    let sub_key = to_wide(r"HARDWARE\DESCRIPTION\System\CentralProcessor\0");
+    let value_name = to_wide(value);
+    let mut buf = [0u16; 256];
+    let mut size = (buf.len() * 2) as c_ulong;
+
+    // SAFETY: `sub_key`/`value_name` are NUL-terminated wide strings and
+    // `buf`/`size` describe a buffer sized for the call to write into.
+    let rc = unsafe {
+        RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            sub_key.as_ptr(),
+            value_name.as_ptr(),
+            RRF_RT_REG_SZ,
+            std::ptr::null_mut(),
+            buf.as_mut_ptr() as *mut c_void,
+            &mut size,
+        )
+    };
+    if rc != 0 {
+        return None;
+    }
+    Some(from_wide(&buf))
+}
+
+/// Reads a `REG_DWORD` value under `HARDWARE\DESCRIPTION\System\CentralProcessor\0`.
+fn cpu_registry_dword(value: &str) -> Option<u32> {
+    let sub_key = to_wide(r"HARDWARE\DESCRIPTION\System\CentralProcessor\0");
+    let value_name = to_wide(value);
+    let mut data = 0u32;
+    let mut size = std::mem::size_of::<u32>() as c_ulong;
+
+    // SAFETY: `data`/`size` describe a 4-byte buffer matching `RRF_RT_REG_DWORD`.
+    let rc = unsafe {
+        RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            sub_key.as_ptr(),
+            value_name.as_ptr(),
+            RRF_RT_REG_DWORD,
+            std::ptr::null_mut(),
+            &mut data as *mut u32 as *mut c_void,
+            &mut size,
+        )
+    };
+    (rc == 0).then_some(data)
+}
+
+/// Reads system info through the native Win32 APIs (`GlobalMemoryStatusEx`,
+/// `GetSystemPowerStatus`, `RegGetValueW`, ...) instead of shelling out to
+/// `powershell`/CIM, the way `LinuxInfoSource` reads `/proc` directly.
+pub struct WindowsInfoSource;
+
+impl WindowsInfoSource {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl InfoSource for WindowsInfoSource {
+    fn hostname(&self) -> String {
+        let mut buf = [0u16; 256];
+        let mut size = buf.len() as u32;
+        // SAFETY: `buf`/`size` describe a buffer long enough for any DNS
+        // hostname; `GetComputerNameExW` truncates rather than overflowing it.
+        let ok = unsafe { GetComputerNameExW(COMPUTER_NAME_DNS_HOSTNAME, buf.as_mut_ptr(), &mut size) };
+        if ok == 0 {
+            return "unknown".to_string();
+        }
+        from_wide(&buf)
+    }
+
+    fn os_info(&self) -> String {
+        let mut info = OsVersionInfoW {
+            dw_os_version_info_size: std::mem::size_of::<OsVersionInfoW>() as u32,
+            ..Default::default()
+        };
+        // SAFETY: `info` is zero-initialized and sized via `dw_os_version_info_size`.
+        if unsafe { RtlGetVersion(&mut info) } != 0 {
+            return format!("{} {}", std::env::consts::OS, std::env::consts::ARCH);
+        }
+        format!(
+            "Windows {}.{}.{}",
+            info.dw_major_version, info.dw_minor_version, info.dw_build_number
+        )
+    }
+
+    fn kernel(&self) -> String {
+        let mut info = OsVersionInfoW {
+            dw_os_version_info_size: std::mem::size_of::<OsVersionInfoW>() as u32,
+            ..Default::default()
+        };
+        // SAFETY: same call as `os_info`; reused rather than cached since it's
+        // a single in-process syscall with no subprocess cost to amortize.
+        if unsafe { RtlGetVersion(&mut info) } != 0 {
+            return "unknown".to_string();
+        }
+        format!("{}.{}.{}", info.dw_major_version, info.dw_minor_version, info.dw_build_number)
+    }
+
+    fn uptime_secs(&self) -> Option<f64> {
+        // SAFETY: `GetTickCount64` takes no arguments and cannot fail.
+        Some(unsafe { GetTickCount64() } as f64 / 1000.0)
+    }
+
+    fn cpu_info(&self) -> CpuInfo {
+        let model = cpu_registry_string("ProcessorNameString").unwrap_or_else(|| "unknown".to_string());
+        let model = truncate_cpu_model(&model);
+        let speed_ghz = cpu_registry_dword("~MHz")
+            .map(|mhz| mhz as f64 / 1000.0)
+            .unwrap_or(0.0);
+
+        let mut info = SystemInfo::default();
+        // SAFETY: `info` is a valid, zero-initialized `SYSTEM_INFO` buffer.
+        unsafe { GetSystemInfo(&mut info) };
+        let cores = info.dw_number_of_processors as usize;
+
+        CpuInfo {
+            model,
+            cores,
+            speed_ghz,
+        }
+    }
+
+    fn memory_kib(&self) -> (u64, u64) {
+        let mut status = MaybeUninit::<MemoryStatusEx>::uninit();
+        // SAFETY: `dw_length` is written before the call, as `GlobalMemoryStatusEx` requires.
+        unsafe {
+            (*status.as_mut_ptr()).dw_length = std::mem::size_of::<MemoryStatusEx>() as u32;
+        }
+        // SAFETY: `status` points at a buffer of the declared size.
+        let ok = unsafe { GlobalMemoryStatusEx(status.as_mut_ptr()) };
+        if ok == 0 {
+            return (0, 0);
+        }
+        // SAFETY: the call succeeded, so `status` is fully initialized.
+        let status = unsafe { status.assume_init() };
+
+        let total_kib = status.ull_total_phys / 1024;
+        let avail_kib = status.ull_avail_phys / 1024;
+        (total_kib.saturating_sub(avail_kib), total_kib)
+    }
+
+    fn battery(&self) -> Option<BatteryInfo> {
+        let mut status = SystemPowerStatus::default();
+        // SAFETY: `status` is a valid, zero-initialized `SYSTEM_POWER_STATUS` buffer.
+        let ok = unsafe { GetSystemPowerStatus(&mut status) };
+        if ok == 0 || status.battery_flag == BATTERY_FLAG_NO_BATTERY || status.battery_life_percent > 100 {
+            return None;
+        }
+
+        let percent = status.battery_life_percent;
+        let status_str = if status.battery_flag & BATTERY_FLAG_CHARGING != 0 {
+            "Charging"
+        } else if percent >= 100 {
+            "Full"
+        } else if status.ac_line_status == 1 {
+            "On AC"
+        } else {
+            "Discharging"
+        }
+        .to_string();
+
+        Some(BatteryInfo {
+            percent,
+            status: status_str,
+        })
+    }
+
+    fn disk_kib(&self) -> (u64, u64) {
+        let root = to_wide(r"C:\");
+        let (mut free, mut total, mut total_free) = (0u64, 0u64, 0u64);
+        // SAFETY: `root` is a NUL-terminated wide string and the three `u64`
+        // out-params are valid writable locations.
+        let ok = unsafe { GetDiskFreeSpaceExW(root.as_ptr(), &mut free, &mut total, &mut total_free) };
+        if ok == 0 {
+            return (0, 0);
+        }
+
+        let total_kib = total / 1024;
+        let free_kib = total_free / 1024;
+        (total_kib.saturating_sub(free_kib), total_kib)
+    }
+
+    fn gpu(&self) -> Option<String> {
+        let mut info = DisplayDeviceW {
+            cb: std::mem::size_of::<DisplayDeviceW>() as u32,
+            ..Default::default()
+        };
+        // SAFETY: `info.cb` is set to the struct size as `EnumDisplayDevicesW` requires.
+        let ok = unsafe { EnumDisplayDevicesW(std::ptr::null(), 0, &mut info, 0) };
+        if ok == 0 {
+            return None;
+        }
+        Some(from_wide(&info.device_string))
+    }
+}
@@ -0,0 +1,18 @@
+//! Shared formatting helpers for byte-sized quantities (memory, disk, swap).
+
+const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+
+/// Formats a size given in KiB as a human-readable string using the largest
+/// unit that keeps the value at or above 1, with two decimal places
+/// (e.g. `512.00 KiB`, `3.40 GiB`).
+pub fn format_bytes(kib: u64) -> String {
+    let mut value = kib as f64;
+    let mut unit_index = 0;
+
+    while value >= 1024.0 && unit_index < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit_index += 1;
+    }
+
+    format!("{:.2} {}", value, UNITS[unit_index])
+}